@@ -0,0 +1,118 @@
+use axum::{response::IntoResponse, http::StatusCode, Json};
+use serde::Deserialize;
+use serde_json::json;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program,
+};
+use std::str::FromStr;
+use base64::{engine::general_purpose, Engine as _};
+
+use crate::AccountMetaInfo;
+
+const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+const ASSOCIATED_TOKEN_PROGRAM_ID: &str = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+
+/// "Create idempotent" associated-token-account instruction discriminant.
+const CREATE_IDEMPOTENT: u8 = 1;
+
+/// Derives the associated token account for `(owner, mint)` and its bump
+/// seed, following the standard ATA seed layout.
+pub(crate) fn derive_ata(owner: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    let token_program_id = Pubkey::from_str(TOKEN_PROGRAM_ID).unwrap();
+    let ata_program_id = Pubkey::from_str(ASSOCIATED_TOKEN_PROGRAM_ID).unwrap();
+
+    Pubkey::find_program_address(
+        &[owner.as_ref(), token_program_id.as_ref(), mint.as_ref()],
+        &ata_program_id,
+    )
+}
+
+/// Builds the "create idempotent" instruction for the ATA of `(owner, mint)`,
+/// funded by `funder`.
+pub(crate) fn create_idempotent_instruction(
+    funder: &Pubkey,
+    owner: &Pubkey,
+    mint: &Pubkey,
+) -> Instruction {
+    let (ata, _bump) = derive_ata(owner, mint);
+    let token_program_id = Pubkey::from_str(TOKEN_PROGRAM_ID).unwrap();
+    let ata_program_id = Pubkey::from_str(ASSOCIATED_TOKEN_PROGRAM_ID).unwrap();
+
+    let accounts = vec![
+        AccountMeta::new(*funder, true),
+        AccountMeta::new(ata, false),
+        AccountMeta::new_readonly(*owner, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(token_program_id, false),
+    ];
+
+    Instruction::new_with_bytes(ata_program_id, &[CREATE_IDEMPOTENT], accounts)
+}
+
+#[derive(Deserialize)]
+pub struct DeriveAtaRequest {
+    funder: String,
+    owner: String,
+    mint: String,
+}
+
+pub async fn create_ata(Json(payload): Json<DeriveAtaRequest>) -> impl IntoResponse {
+    let funder = match Pubkey::from_str(&payload.funder) {
+        Ok(pk) => pk,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "success": false, "error": "Invalid funder pubkey" })),
+            );
+        }
+    };
+
+    let owner = match Pubkey::from_str(&payload.owner) {
+        Ok(pk) => pk,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "success": false, "error": "Invalid owner pubkey" })),
+            );
+        }
+    };
+
+    let mint = match Pubkey::from_str(&payload.mint) {
+        Ok(pk) => pk,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "success": false, "error": "Invalid mint pubkey" })),
+            );
+        }
+    };
+
+    let (ata, bump) = derive_ata(&owner, &mint);
+    let instruction = create_idempotent_instruction(&funder, &owner, &mint);
+
+    let account_info: Vec<AccountMetaInfo> = instruction
+        .accounts
+        .iter()
+        .map(|acc| AccountMetaInfo {
+            pubkey: acc.pubkey.to_string(),
+            is_signer: acc.is_signer,
+            is_writable: acc.is_writable,
+        })
+        .collect();
+
+    let response = json!({
+        "success": true,
+        "data": {
+            "ata": ata.to_string(),
+            "bump": bump,
+            "program_id": instruction.program_id.to_string(),
+            "accounts": account_info,
+            "instruction_data": general_purpose::STANDARD.encode(instruction.data)
+        }
+    });
+
+    (StatusCode::OK, Json(response))
+}
@@ -0,0 +1,34 @@
+use std::env;
+
+const DEFAULT_CLUSTER_URL: &str = "https://api.devnet.solana.com";
+
+/// Shared state handed to handlers that need to talk to a cluster.
+#[derive(Clone)]
+pub struct AppState {
+    pub cluster_url: String,
+}
+
+impl AppState {
+    /// Resolves the cluster URL from (in priority order) a `--cluster-url`
+    /// CLI flag, the `SOLANA_RPC_URL` env var, or the devnet default.
+    pub fn from_env_and_args() -> Self {
+        let cluster_url = cluster_url_from_args(env::args())
+            .or_else(|| env::var("SOLANA_RPC_URL").ok())
+            .unwrap_or_else(|| DEFAULT_CLUSTER_URL.to_string());
+
+        AppState { cluster_url }
+    }
+}
+
+fn cluster_url_from_args(args: impl Iterator<Item = String>) -> Option<String> {
+    let mut args = args.peekable();
+    while let Some(arg) = args.next() {
+        if arg == "--cluster-url" {
+            return args.next();
+        }
+        if let Some(value) = arg.strip_prefix("--cluster-url=") {
+            return Some(value.to_string());
+        }
+    }
+    None
+}
@@ -0,0 +1,323 @@
+use axum::{response::IntoResponse, http::StatusCode, Json};
+use chrono::DateTime;
+use serde::Deserialize;
+use serde_json::json;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+};
+use std::str::FromStr;
+use base64::{engine::general_purpose, Engine as _};
+
+use crate::instruction_info;
+
+/// Placeholder program id for the escrow program that holds
+/// conditional/witness-released payments.
+const ESCROW_PROGRAM_ID: &str = "Escrow1111111111111111111111111111111111111";
+
+/// Fixed size of an escrow account: initialized flag, `to` pubkey,
+/// lamports, and the (optional) timestamp and signature conditions.
+const ESCROW_ACCOUNT_LEN: u64 = 1 + 32 + 8 + (1 + 32 + 8) + (1 + 32);
+
+const INITIALIZE_ESCROW: u8 = 0;
+const APPLY_SIGNATURE: u8 = 1;
+const APPLY_TIMESTAMP: u8 = 2;
+
+#[derive(Deserialize)]
+pub struct SendConditionalRequest {
+    from: String,
+    to: String,
+    lamports: u64,
+    /// RFC3339 timestamp; the payment releases once a witness-signed
+    /// apply-timestamp instruction reports a time at or after this.
+    after: Option<String>,
+    /// Pubkey of the account whose signature (or timestamp attestation)
+    /// releases the payment.
+    witness: Option<String>,
+}
+
+pub async fn send_conditional(Json(payload): Json<SendConditionalRequest>) -> impl IntoResponse {
+    let from = match Pubkey::from_str(&payload.from) {
+        Ok(pk) => pk,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "success": false, "error": "Invalid sender pubkey" })),
+            );
+        }
+    };
+
+    let to = match Pubkey::from_str(&payload.to) {
+        Ok(pk) => pk,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "success": false, "error": "Invalid recipient pubkey" })),
+            );
+        }
+    };
+
+    if payload.after.is_none() && payload.witness.is_none() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(json!({ "success": false, "error": "Must specify an `after` timestamp and/or a `witness`" })),
+        );
+    }
+
+    let witness = match &payload.witness {
+        Some(witness) => match Pubkey::from_str(witness) {
+            Ok(pk) => Some(pk),
+            Err(_) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "success": false, "error": "Invalid witness pubkey" })),
+                );
+            }
+        },
+        None => None,
+    };
+
+    let after_unix_time = match &payload.after {
+        Some(after) => match DateTime::parse_from_rfc3339(after) {
+            Ok(dt) => Some(dt.timestamp()),
+            Err(_) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "success": false, "error": "Invalid `after` RFC3339 timestamp" })),
+                );
+            }
+        },
+        None => None,
+    };
+
+    if after_unix_time.is_some() && witness.is_none() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(json!({ "success": false, "error": "A timestamp condition requires a `witness`" })),
+        );
+    }
+
+    let escrow_program_id = Pubkey::from_str(ESCROW_PROGRAM_ID).unwrap();
+    let escrow = Keypair::new();
+    let escrow_pubkey = escrow.pubkey();
+
+    let create_account_ix = system_instruction::create_account(
+        &from,
+        &escrow_pubkey,
+        0,
+        ESCROW_ACCOUNT_LEN,
+        &escrow_program_id,
+    );
+
+    let transfer_ix = system_instruction::transfer(&from, &escrow_pubkey, payload.lamports);
+
+    let mut data = vec![INITIALIZE_ESCROW];
+    data.extend_from_slice(to.as_ref());
+    data.extend_from_slice(&payload.lamports.to_le_bytes());
+    match after_unix_time {
+        Some(unix_time) => {
+            data.push(1);
+            data.extend_from_slice(witness.unwrap().as_ref());
+            data.extend_from_slice(&unix_time.to_le_bytes());
+        }
+        None => data.push(0),
+    }
+    match witness {
+        Some(witness) => {
+            data.push(1);
+            data.extend_from_slice(witness.as_ref());
+        }
+        None => data.push(0),
+    }
+
+    let initialize_ix = Instruction::new_with_bytes(
+        escrow_program_id,
+        &data,
+        vec![
+            AccountMeta::new(escrow_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+
+    let response = json!({
+        "success": true,
+        "data": {
+            "escrow": escrow_pubkey.to_string(),
+            "escrow_secret": general_purpose::STANDARD.encode(escrow.to_bytes()),
+            "instructions": [
+                instruction_info(&create_account_ix),
+                instruction_info(&transfer_ix),
+                instruction_info(&initialize_ix),
+            ]
+        }
+    });
+
+    (StatusCode::OK, Json(response))
+}
+
+#[derive(Deserialize)]
+pub struct ApplyTimestampRequest {
+    escrow: String,
+    to: String,
+    witness: String,
+    timestamp: String,
+}
+
+pub async fn apply_timestamp(Json(payload): Json<ApplyTimestampRequest>) -> impl IntoResponse {
+    let escrow = match Pubkey::from_str(&payload.escrow) {
+        Ok(pk) => pk,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "success": false, "error": "Invalid escrow pubkey" })),
+            );
+        }
+    };
+
+    let to = match Pubkey::from_str(&payload.to) {
+        Ok(pk) => pk,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "success": false, "error": "Invalid recipient pubkey" })),
+            );
+        }
+    };
+
+    let witness = match Pubkey::from_str(&payload.witness) {
+        Ok(pk) => pk,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "success": false, "error": "Invalid witness pubkey" })),
+            );
+        }
+    };
+
+    let unix_time = match DateTime::parse_from_rfc3339(&payload.timestamp) {
+        Ok(dt) => dt.timestamp(),
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "success": false, "error": "Invalid RFC3339 timestamp" })),
+            );
+        }
+    };
+
+    let escrow_program_id = Pubkey::from_str(ESCROW_PROGRAM_ID).unwrap();
+
+    let mut data = vec![APPLY_TIMESTAMP];
+    data.extend_from_slice(&unix_time.to_le_bytes());
+
+    let instruction = Instruction::new_with_bytes(
+        escrow_program_id,
+        &data,
+        vec![
+            AccountMeta::new_readonly(witness, true),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(to, false),
+        ],
+    );
+
+    let response = json!({
+        "success": true,
+        "data": instruction_info(&instruction)
+    });
+
+    (StatusCode::OK, Json(response))
+}
+
+#[derive(Deserialize)]
+pub struct ApplySignatureRequest {
+    escrow: String,
+    to: String,
+    witness: String,
+}
+
+pub async fn apply_signature(Json(payload): Json<ApplySignatureRequest>) -> impl IntoResponse {
+    let escrow = match Pubkey::from_str(&payload.escrow) {
+        Ok(pk) => pk,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "success": false, "error": "Invalid escrow pubkey" })),
+            );
+        }
+    };
+
+    let to = match Pubkey::from_str(&payload.to) {
+        Ok(pk) => pk,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "success": false, "error": "Invalid recipient pubkey" })),
+            );
+        }
+    };
+
+    let witness = match Pubkey::from_str(&payload.witness) {
+        Ok(pk) => pk,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "success": false, "error": "Invalid witness pubkey" })),
+            );
+        }
+    };
+
+    let escrow_program_id = Pubkey::from_str(ESCROW_PROGRAM_ID).unwrap();
+
+    let instruction = Instruction::new_with_bytes(
+        escrow_program_id,
+        &[APPLY_SIGNATURE],
+        vec![
+            AccountMeta::new_readonly(witness, true),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(to, false),
+        ],
+    );
+
+    let response = json!({
+        "success": true,
+        "data": instruction_info(&instruction)
+    });
+
+    (StatusCode::OK, Json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn send_conditional_builds_instructions_without_panicking() {
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let witness = Pubkey::new_unique();
+
+        let request = SendConditionalRequest {
+            from: from.to_string(),
+            to: to.to_string(),
+            lamports: 1_000_000,
+            after: None,
+            witness: Some(witness.to_string()),
+        };
+
+        let response = send_conditional(Json(request)).await.into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn apply_signature_builds_instruction_without_panicking() {
+        let request = ApplySignatureRequest {
+            escrow: Pubkey::new_unique().to_string(),
+            to: Pubkey::new_unique().to_string(),
+            witness: Pubkey::new_unique().to_string(),
+        };
+
+        let response = apply_signature(Json(request)).await.into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
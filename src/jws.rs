@@ -0,0 +1,66 @@
+use axum::{response::IntoResponse, http::StatusCode, Json};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use ed25519_dalek::{Keypair as DalekKeypair, Signer as DalekSigner};
+use base64::{engine::general_purpose, Engine as _};
+
+const PROTECTED_HEADER: &str = r#"{"alg":"EdDSA"}"#;
+
+/// The JWS signing input is `base64url(protected) + "." + base64url(payload)`.
+pub(crate) fn signing_input(protected_b64: &str, payload_b64: &str) -> String {
+    format!("{protected_b64}.{payload_b64}")
+}
+
+#[derive(Deserialize)]
+pub struct SignMessageRequest {
+    /// Base64 encoded 64-byte Ed25519 secret key.
+    secret: String,
+    payload: String,
+}
+
+#[derive(Serialize)]
+struct FlattenedJws {
+    protected: String,
+    payload: String,
+    signature: String,
+}
+
+pub async fn sign_message(Json(payload): Json<SignMessageRequest>) -> impl IntoResponse {
+    let secret_bytes = match general_purpose::STANDARD.decode(&payload.secret) {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "success": false, "error": "Invalid base64 secret key" })),
+            );
+        }
+    };
+
+    let keypair = match DalekKeypair::from_bytes(&secret_bytes) {
+        Ok(keypair) => keypair,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "success": false, "error": "Invalid secret key bytes" })),
+            );
+        }
+    };
+
+    let protected_b64 = general_purpose::URL_SAFE_NO_PAD.encode(PROTECTED_HEADER);
+    let payload_b64 = general_purpose::URL_SAFE_NO_PAD.encode(payload.payload.as_bytes());
+    let signing_input = signing_input(&protected_b64, &payload_b64);
+
+    let signature = keypair.sign(signing_input.as_bytes());
+    let signature_b64 = general_purpose::URL_SAFE_NO_PAD.encode(signature.to_bytes());
+
+    let response = json!({
+        "success": true,
+        "data": FlattenedJws {
+            protected: protected_b64,
+            payload: payload_b64,
+            signature: signature_b64,
+        }
+    });
+
+    (StatusCode::OK, Json(response))
+}
@@ -1,5 +1,5 @@
 use axum::{
-    routing::{post},
+    routing::{get, post},
     Json, Router, response::IntoResponse, http::StatusCode,
 };
 use serde::{Deserialize, Serialize};
@@ -16,6 +16,16 @@ use base64::{engine::general_purpose, Engine as _};
 use ed25519_dalek::{PublicKey as DalekPublicKey, Signature as DalekSignature, Verifier};
 use bs58;
 
+mod ata;
+mod config;
+mod escrow;
+mod jws;
+mod nft;
+mod rpc;
+mod sign;
+
+use config::AppState;
+
 async fn generate_keypair() -> impl IntoResponse {
     let keypair = Keypair::new();
     let pubkey = keypair.pubkey().to_string();
@@ -40,10 +50,35 @@ struct CreateTokenRequest {
 }
 
 #[derive(Serialize)]
-struct AccountMetaInfo {
-    pubkey: String,
-    is_signer: bool,
-    is_writable: bool,
+pub(crate) struct AccountMetaInfo {
+    pub(crate) pubkey: String,
+    pub(crate) is_signer: bool,
+    pub(crate) is_writable: bool,
+}
+
+/// A fully assembled instruction, shaped for JSON responses: the program
+/// id, its account metas, and the raw instruction bytes (base64).
+#[derive(Serialize)]
+pub(crate) struct InstructionInfo {
+    pub(crate) program_id: String,
+    pub(crate) accounts: Vec<AccountMetaInfo>,
+    pub(crate) instruction_data: String,
+}
+
+pub(crate) fn instruction_info(instruction: &Instruction) -> InstructionInfo {
+    InstructionInfo {
+        program_id: instruction.program_id.to_string(),
+        accounts: instruction
+            .accounts
+            .iter()
+            .map(|acc| AccountMetaInfo {
+                pubkey: acc.pubkey.to_string(),
+                is_signer: acc.is_signer,
+                is_writable: acc.is_writable,
+            })
+            .collect(),
+        instruction_data: general_purpose::STANDARD.encode(&instruction.data),
+    }
 }
 
 async fn create_token(Json(payload): Json<CreateTokenRequest>) -> impl IntoResponse {
@@ -106,6 +141,8 @@ struct MintTokenRequest {
     destination: String,
     authority: String,
     amount: u64,
+    #[serde(default)]
+    as_ata: bool,
 }
 
 async fn mint_token(Json(payload): Json<MintTokenRequest>) -> impl IntoResponse {
@@ -139,6 +176,12 @@ async fn mint_token(Json(payload): Json<MintTokenRequest>) -> impl IntoResponse
         }
     };
 
+    let destination = if payload.as_ata {
+        ata::derive_ata(&destination, &mint).0
+    } else {
+        destination
+    };
+
     let program_id = Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap();
 
     let accounts = vec![
@@ -176,6 +219,11 @@ struct VerifyMessageRequest {
     message: String,
     signature: String,
     pubkey: String,
+    /// When set, `message` is treated as a JWS payload and `signature` as
+    /// the base64url-encoded signature over `protected.payload`.
+    #[serde(default)]
+    accept_jws: bool,
+    protected: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -206,14 +254,38 @@ async fn verify_message(Json(payload): Json<VerifyMessageRequest>) -> impl IntoR
         }
     };
 
-    let signature_bytes = match general_purpose::STANDARD.decode(&payload.signature) {
-        Ok(bytes) => bytes,
-        Err(_) => {
-            return (
-                StatusCode::BAD_REQUEST,
-                Json(json!({ "success": false, "error": "Invalid base64 signature" })),
-            );
-        }
+    let (signing_bytes, signature_bytes) = if payload.accept_jws {
+        let protected = match &payload.protected {
+            Some(protected) => protected,
+            None => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "success": false, "error": "Missing protected header for JWS verification" })),
+                );
+            }
+        };
+        let payload_b64 = general_purpose::URL_SAFE_NO_PAD.encode(payload.message.as_bytes());
+        let signature_bytes = match general_purpose::URL_SAFE_NO_PAD.decode(&payload.signature) {
+            Ok(bytes) => bytes,
+            Err(_) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "success": false, "error": "Invalid base64url signature" })),
+                );
+            }
+        };
+        (jws::signing_input(protected, &payload_b64).into_bytes(), signature_bytes)
+    } else {
+        let signature_bytes = match general_purpose::STANDARD.decode(&payload.signature) {
+            Ok(bytes) => bytes,
+            Err(_) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "success": false, "error": "Invalid base64 signature" })),
+                );
+            }
+        };
+        (payload.message.as_bytes().to_vec(), signature_bytes)
     };
 
     let signature = match DalekSignature::from_bytes(&signature_bytes) {
@@ -226,7 +298,7 @@ async fn verify_message(Json(payload): Json<VerifyMessageRequest>) -> impl IntoR
         }
     };
 
-    let valid = pubkey.verify(payload.message.as_bytes(), &signature).is_ok();
+    let valid = pubkey.verify(&signing_bytes, &signature).is_ok();
 
     let response_data = VerifyMessageResponseData {
         valid,
@@ -311,6 +383,8 @@ struct SendTokenRequest {
     mint: String,
     owner: String,
     amount: u64,
+    #[serde(default)]
+    as_ata: bool,
 }
 
 #[derive(Serialize)]
@@ -357,9 +431,22 @@ async fn send_token(Json(payload): Json<SendTokenRequest>) -> impl IntoResponse
         );
     }
 
+    let (source, destination) = if payload.as_ata {
+        (
+            ata::derive_ata(&owner, &mint).0,
+            ata::derive_ata(&destination, &mint).0,
+        )
+    } else {
+        (owner, destination)
+    };
+
     let program_id = Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap();
 
     let accounts = vec![
+        SendTokenAccountInfo {
+            pubkey: source.to_string(),
+            isSigner: false,
+        },
         SendTokenAccountInfo {
             pubkey: destination.to_string(),
             isSigner: false,
@@ -392,13 +479,26 @@ async fn send_token(Json(payload): Json<SendTokenRequest>) -> impl IntoResponse
 
 #[tokio::main]
 async fn main() {
+    let state = AppState::from_env_and_args();
+
     let app = Router::new()
         .route("/keypair", post(generate_keypair))
         .route("/token/create", post(create_token))
         .route("/token/mint", post(mint_token))
+        .route("/token/ata", post(ata::create_ata))
+        .route("/nft/create", post(nft::create_nft))
         .route("/message/verify", post(verify_message))
+        .route("/message/sign", post(jws::sign_message))
         .route("/send/sol", post(send_sol))
-        .route("/send/token", post(send_token));
+        .route("/send/token", post(send_token))
+        .route("/send/conditional", post(escrow::send_conditional))
+        .route("/send/apply-timestamp", post(escrow::apply_timestamp))
+        .route("/send/apply-signature", post(escrow::apply_signature))
+        .route("/tx/submit", post(rpc::submit_transaction))
+        .route("/tx/sign", post(sign::sign_transaction))
+        .route("/account/:pubkey", get(rpc::get_account_info))
+        .route("/balance/:pubkey", get(rpc::get_balance))
+        .with_state(state);
 
     let address = SocketAddr::from(([0, 0, 0, 0], 3000));
     println!("Server running at http://{}", address);
@@ -0,0 +1,142 @@
+use axum::{response::IntoResponse, http::StatusCode, Json};
+use serde::Deserialize;
+use serde_json::json;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+};
+use std::str::FromStr;
+use base64::{engine::general_purpose, Engine as _};
+
+use crate::{ata, instruction_info};
+
+const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+const METADATA_PROGRAM_ID: &str = "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s";
+
+/// `CreateMetadataAccountV3` instruction discriminant in the Token
+/// Metadata program.
+const CREATE_METADATA_ACCOUNT_V3: u8 = 33;
+
+/// Borsh-encodes a UTF-8 string as a u32 length prefix followed by its bytes.
+fn write_borsh_string(buf: &mut Vec<u8>, value: &str) {
+    buf.extend_from_slice(&(value.len() as u32).to_le_bytes());
+    buf.extend_from_slice(value.as_bytes());
+}
+
+#[derive(Deserialize)]
+pub struct CreateNftRequest {
+    name: String,
+    symbol: String,
+    uri: String,
+    seller_fee_basis_points: u16,
+    mint_authority: String,
+    update_authority: String,
+}
+
+pub async fn create_nft(Json(payload): Json<CreateNftRequest>) -> impl IntoResponse {
+    let mint_authority = match Pubkey::from_str(&payload.mint_authority) {
+        Ok(pk) => pk,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "success": false, "error": "Invalid mintAuthority pubkey" })),
+            );
+        }
+    };
+
+    let update_authority = match Pubkey::from_str(&payload.update_authority) {
+        Ok(pk) => pk,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "success": false, "error": "Invalid updateAuthority pubkey" })),
+            );
+        }
+    };
+
+    let token_program_id = Pubkey::from_str(TOKEN_PROGRAM_ID).unwrap();
+    let metadata_program_id = Pubkey::from_str(METADATA_PROGRAM_ID).unwrap();
+
+    let mint = Keypair::new();
+    let mint_pubkey = mint.pubkey();
+
+    // 1. Initialize a 0-decimal mint owned by the token program.
+    let mut initialize_mint_data = vec![0u8, 0u8];
+    initialize_mint_data.extend_from_slice(mint_authority.as_ref());
+    initialize_mint_data.push(0);
+    let initialize_mint_ix = Instruction::new_with_bytes(
+        token_program_id,
+        &initialize_mint_data,
+        vec![
+            AccountMeta::new(mint_pubkey, true),
+            AccountMeta::new_readonly(mint_authority, false),
+        ],
+    );
+
+    // 2. Create the mint authority's associated token account.
+    let create_ata_ix = ata::create_idempotent_instruction(&mint_authority, &mint_authority, &mint_pubkey);
+    let (ata_pubkey, _bump) = ata::derive_ata(&mint_authority, &mint_pubkey);
+
+    // 3. Mint the single token into that account.
+    let mut mint_to_data = vec![7u8];
+    mint_to_data.extend_from_slice(&1u64.to_le_bytes());
+    let mint_to_ix = Instruction::new_with_bytes(
+        token_program_id,
+        &mint_to_data,
+        vec![
+            AccountMeta::new(mint_pubkey, false),
+            AccountMeta::new(ata_pubkey, false),
+            AccountMeta::new_readonly(mint_authority, true),
+        ],
+    );
+
+    // 4. Create the Metaplex metadata account for the mint.
+    let (metadata_pubkey, _bump) = Pubkey::find_program_address(
+        &[b"metadata", metadata_program_id.as_ref(), mint_pubkey.as_ref()],
+        &metadata_program_id,
+    );
+
+    let mut metadata_data = vec![CREATE_METADATA_ACCOUNT_V3];
+    write_borsh_string(&mut metadata_data, &payload.name);
+    write_borsh_string(&mut metadata_data, &payload.symbol);
+    write_borsh_string(&mut metadata_data, &payload.uri);
+    metadata_data.extend_from_slice(&payload.seller_fee_basis_points.to_le_bytes());
+    metadata_data.push(0); // creators: None
+    metadata_data.push(0); // collection: None
+    metadata_data.push(0); // uses: None
+    metadata_data.push(1); // is_mutable: true
+    metadata_data.push(0); // collection_details: None
+
+    let create_metadata_ix = Instruction::new_with_bytes(
+        metadata_program_id,
+        &metadata_data,
+        vec![
+            AccountMeta::new(metadata_pubkey, false),
+            AccountMeta::new_readonly(mint_pubkey, false),
+            AccountMeta::new_readonly(mint_authority, true),
+            AccountMeta::new(mint_authority, true),
+            AccountMeta::new_readonly(update_authority, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+
+    let response = json!({
+        "success": true,
+        "data": {
+            "mint": mint_pubkey.to_string(),
+            "mint_secret": general_purpose::STANDARD.encode(mint.to_bytes()),
+            "metadata": metadata_pubkey.to_string(),
+            "ata": ata_pubkey.to_string(),
+            "instructions": [
+                instruction_info(&initialize_mint_ix),
+                instruction_info(&create_ata_ix),
+                instruction_info(&mint_to_ix),
+                instruction_info(&create_metadata_ix),
+            ]
+        }
+    });
+
+    (StatusCode::OK, Json(response))
+}
@@ -0,0 +1,275 @@
+use axum::{extract::{Path, State}, response::IntoResponse, http::StatusCode, Json};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    message::Message,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+    transaction::Transaction,
+};
+use std::str::FromStr;
+use std::time::Duration;
+use base64::{engine::general_purpose, Engine as _};
+
+use crate::config::AppState;
+
+const CONFIRMATION_TIMEOUT: Duration = Duration::from_secs(30);
+const CONFIRMATION_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Mirrors `AccountMetaInfo`, but as request input rather than response
+/// output.
+#[derive(Deserialize)]
+struct AccountMetaInput {
+    pubkey: String,
+    is_signer: bool,
+    is_writable: bool,
+}
+
+/// The decomposed `{program_id, accounts, instruction_data}` shape returned
+/// by `/token/create`, `/token/mint`, `/token/ata`, `/nft/create` and the
+/// `/send/conditional` and `/send/apply-*` endpoints, so an instruction
+/// produced by one of those can be submitted here as-is. `/send/sol` and
+/// `/send/token` return their accounts in a different shape and are not
+/// accepted unchanged.
+#[derive(Deserialize)]
+struct InstructionInput {
+    program_id: String,
+    accounts: Vec<AccountMetaInput>,
+    instruction_data: String,
+}
+
+#[derive(Deserialize)]
+pub struct SubmitTransactionRequest {
+    instructions: Vec<InstructionInput>,
+    /// Base64 encoded secret keys for every required signer; the first
+    /// entry is treated as the fee payer.
+    signers: Vec<String>,
+}
+
+pub async fn submit_transaction(
+    State(state): State<AppState>,
+    Json(payload): Json<SubmitTransactionRequest>,
+) -> impl IntoResponse {
+    if payload.signers.is_empty() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(json!({ "success": false, "error": "At least one signer is required" })),
+        );
+    }
+
+    let mut instructions = Vec::with_capacity(payload.instructions.len());
+    for instruction in &payload.instructions {
+        let program_id = match Pubkey::from_str(&instruction.program_id) {
+            Ok(pk) => pk,
+            Err(_) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "success": false, "error": "Invalid instruction program_id" })),
+                );
+            }
+        };
+
+        let mut accounts = Vec::with_capacity(instruction.accounts.len());
+        for account in &instruction.accounts {
+            let pubkey = match Pubkey::from_str(&account.pubkey) {
+                Ok(pk) => pk,
+                Err(_) => {
+                    return (
+                        StatusCode::BAD_REQUEST,
+                        Json(json!({ "success": false, "error": "Invalid instruction account pubkey" })),
+                    );
+                }
+            };
+            accounts.push(AccountMeta {
+                pubkey,
+                is_signer: account.is_signer,
+                is_writable: account.is_writable,
+            });
+        }
+
+        let data = match general_purpose::STANDARD.decode(&instruction.instruction_data) {
+            Ok(bytes) => bytes,
+            Err(_) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "success": false, "error": "Invalid base64 instruction_data" })),
+                );
+            }
+        };
+
+        instructions.push(Instruction::new_with_bytes(program_id, &data, accounts));
+    }
+
+    let mut signers = Vec::with_capacity(payload.signers.len());
+    for encoded in &payload.signers {
+        let bytes = match general_purpose::STANDARD.decode(encoded) {
+            Ok(bytes) => bytes,
+            Err(_) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "success": false, "error": "Invalid base64 signer secret" })),
+                );
+            }
+        };
+        match Keypair::from_bytes(&bytes) {
+            Ok(keypair) => signers.push(keypair),
+            Err(_) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "success": false, "error": "Invalid signer secret key" })),
+                );
+            }
+        }
+    }
+
+    let client = RpcClient::new(state.cluster_url.clone());
+
+    let blockhash = match client.get_latest_blockhash().await {
+        Ok(hash) => hash,
+        Err(err) => {
+            return (
+                StatusCode::BAD_GATEWAY,
+                Json(json!({ "success": false, "error": format!("Failed to fetch blockhash: {err}") })),
+            );
+        }
+    };
+
+    let fee_payer = signers[0].pubkey();
+    let message = Message::new(&instructions, Some(&fee_payer));
+    let signer_refs: Vec<&Keypair> = signers.iter().collect();
+
+    let mut transaction = Transaction::new_unsigned(message);
+    if let Err(err) = transaction.try_sign(&signer_refs, blockhash) {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(json!({ "success": false, "error": format!("Failed to sign transaction: {err}") })),
+        );
+    }
+
+    let signature = match client.send_transaction(&transaction).await {
+        Ok(signature) => signature,
+        Err(err) => {
+            return (
+                StatusCode::BAD_GATEWAY,
+                Json(json!({ "success": false, "error": format!("Failed to submit transaction: {err}") })),
+            );
+        }
+    };
+
+    let confirmed = wait_for_confirmation(&client, &signature).await;
+
+    let response = json!({
+        "success": true,
+        "data": {
+            "signature": signature.to_string(),
+            "confirmed": confirmed
+        }
+    });
+
+    (StatusCode::OK, Json(response))
+}
+
+async fn wait_for_confirmation(client: &RpcClient, signature: &Signature) -> bool {
+    let deadline = tokio::time::Instant::now() + CONFIRMATION_TIMEOUT;
+
+    loop {
+        if let Ok(statuses) = client.get_signature_statuses(&[*signature]).await {
+            if let Some(Some(status)) = statuses.value.into_iter().next() {
+                if status.satisfies_commitment(solana_sdk::commitment_config::CommitmentConfig::confirmed()) {
+                    return status.err.is_none();
+                }
+            }
+        }
+
+        if tokio::time::Instant::now() >= deadline {
+            return false;
+        }
+
+        tokio::time::sleep(CONFIRMATION_POLL_INTERVAL).await;
+    }
+}
+
+#[derive(Serialize)]
+struct AccountInfoResponse {
+    lamports: u64,
+    owner: String,
+    executable: bool,
+    rent_epoch: u64,
+    data: String,
+}
+
+pub async fn get_account_info(
+    State(state): State<AppState>,
+    Path(pubkey): Path<String>,
+) -> impl IntoResponse {
+    let pubkey = match Pubkey::from_str(&pubkey) {
+        Ok(pk) => pk,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "success": false, "error": "Invalid pubkey" })),
+            );
+        }
+    };
+
+    let client = RpcClient::new(state.cluster_url.clone());
+
+    let account = match client.get_account(&pubkey).await {
+        Ok(account) => account,
+        Err(err) => {
+            return (
+                StatusCode::NOT_FOUND,
+                Json(json!({ "success": false, "error": format!("Account not found: {err}") })),
+            );
+        }
+    };
+
+    let response = json!({
+        "success": true,
+        "data": AccountInfoResponse {
+            lamports: account.lamports,
+            owner: account.owner.to_string(),
+            executable: account.executable,
+            rent_epoch: account.rent_epoch,
+            data: general_purpose::STANDARD.encode(account.data),
+        }
+    });
+
+    (StatusCode::OK, Json(response))
+}
+
+pub async fn get_balance(
+    State(state): State<AppState>,
+    Path(pubkey): Path<String>,
+) -> impl IntoResponse {
+    let pubkey = match Pubkey::from_str(&pubkey) {
+        Ok(pk) => pk,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "success": false, "error": "Invalid pubkey" })),
+            );
+        }
+    };
+
+    let client = RpcClient::new(state.cluster_url.clone());
+
+    let lamports = match client.get_balance(&pubkey).await {
+        Ok(lamports) => lamports,
+        Err(err) => {
+            return (
+                StatusCode::BAD_GATEWAY,
+                Json(json!({ "success": false, "error": format!("Failed to fetch balance: {err}") })),
+            );
+        }
+    };
+
+    let response = json!({
+        "success": true,
+        "data": { "lamports": lamports }
+    });
+
+    (StatusCode::OK, Json(response))
+}
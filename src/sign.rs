@@ -0,0 +1,115 @@
+use axum::{response::IntoResponse, http::StatusCode, Json};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use base64::{engine::general_purpose, Engine as _};
+
+#[derive(Deserialize)]
+pub struct CoSignRequest {
+    /// Base64 + bincode encoded, possibly partially-signed `Transaction`.
+    transaction: String,
+    /// Base64 encoded secret keys to install into the transaction.
+    signers: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct MissingSigner {
+    pubkey: String,
+    signed: bool,
+}
+
+pub async fn sign_transaction(Json(payload): Json<CoSignRequest>) -> impl IntoResponse {
+    let transaction_bytes = match general_purpose::STANDARD.decode(&payload.transaction) {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "success": false, "error": "Invalid base64 transaction" })),
+            );
+        }
+    };
+
+    let mut transaction: Transaction = match bincode::deserialize(&transaction_bytes) {
+        Ok(tx) => tx,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "success": false, "error": "Invalid transaction data" })),
+            );
+        }
+    };
+
+    let mut signers = Vec::with_capacity(payload.signers.len());
+    for encoded in &payload.signers {
+        let bytes = match general_purpose::STANDARD.decode(encoded) {
+            Ok(bytes) => bytes,
+            Err(_) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "success": false, "error": "Invalid base64 signer secret" })),
+                );
+            }
+        };
+        match Keypair::from_bytes(&bytes) {
+            Ok(keypair) => signers.push(keypair),
+            Err(_) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "success": false, "error": "Invalid signer secret key" })),
+                );
+            }
+        }
+    }
+
+    let num_required_signatures = transaction.message.header.num_required_signatures as usize;
+    if num_required_signatures > transaction.message.account_keys.len() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(json!({ "success": false, "error": "Transaction header's num_required_signatures exceeds account_keys" })),
+        );
+    }
+
+    let required_signers = &transaction.message.account_keys[..num_required_signatures];
+
+    for signer in &signers {
+        if !required_signers.contains(&signer.pubkey()) {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({
+                    "success": false,
+                    "error": format!("{} is not a required signer of this transaction", signer.pubkey())
+                })),
+            );
+        }
+    }
+
+    let signer_refs: Vec<&Keypair> = signers.iter().collect();
+    if let Err(err) = transaction.try_partial_sign(&signer_refs, transaction.message.recent_blockhash) {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(json!({ "success": false, "error": format!("Failed to sign transaction: {err}") })),
+        );
+    }
+
+    let missing: Vec<MissingSigner> = required_signers
+        .iter()
+        .zip(transaction.signatures.iter())
+        .map(|(pubkey, signature)| MissingSigner {
+            pubkey: pubkey.to_string(),
+            signed: *signature != solana_sdk::signature::Signature::default(),
+        })
+        .collect();
+
+    let response = json!({
+        "success": true,
+        "data": {
+            "transaction": general_purpose::STANDARD.encode(bincode::serialize(&transaction).unwrap()),
+            "signers": missing
+        }
+    });
+
+    (StatusCode::OK, Json(response))
+}